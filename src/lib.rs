@@ -27,7 +27,7 @@ use std::borrow::Cow;
 /// ```
 ///
 pub fn remove(string: &str) -> Cow<'_, str> {
-    string.chars().filter(|&c| !is_diacritic(c)).collect()
+    remove_iter(string).collect()
 }
 /// Removes hebrew diacritics from a string, while also removing hebrew quotes ('״', '׳').
 /// ```
@@ -37,10 +37,108 @@ pub fn remove(string: &str) -> Cow<'_, str> {
 /// ```
 ///
 pub fn remove_thorough(string: &str) -> Cow<'_, str> {
+    remove_thorough_iter(string).collect()
+}
+
+/// Removes only the categories of hebrew diacritics selected by `opts` from a string.
+///
+/// This lets callers keep marks they care about -- e.g. the shin/sin dots, which
+/// disambiguate 'ש' as ש/שׂ -- while dropping the rest.
+/// ```
+///
+/// let word = niqqud::remove_with("שָׁלוֹם", niqqud::RemoveOptions::VOWELS);
+/// assert_eq!("שׁלום", word); // shin dot is kept, vowels are gone
+/// ```
+///
+pub fn remove_with(string: &str, opts: RemoveOptions) -> Cow<'_, str> {
+    remove_with_iter(string, opts).collect()
+}
+
+/// Streaming variant of [`remove`]: yields the de-niqqud'd characters of a string without
+/// allocating an intermediate `String`, so callers can stream them into their own buffer
+/// or chain further iterator adapters.
+/// ```
+///
+/// let word: String = niqqud::remove_iter("נִקּוּד").collect();
+/// assert_eq!("נקוד", word);
+/// ```
+///
+pub fn remove_iter(string: &str) -> impl Iterator<Item = char> + '_ {
+    remove_with_iter(string, RemoveOptions::ALL_POINTS_AND_ACCENTS)
+}
+
+/// Streaming variant of [`remove_thorough`]. See [`remove_iter`].
+pub fn remove_thorough_iter(string: &str) -> impl Iterator<Item = char> + '_ {
+    remove_with_iter(string, RemoveOptions::ALL)
+}
+
+/// Streaming variant of [`remove_with`]. See [`remove_iter`].
+pub fn remove_with_iter(string: &str, opts: RemoveOptions) -> impl Iterator<Item = char> + '_ {
     string
         .chars()
-        .filter(|&c| !is_diacritic(c) && !is_special(c))
-        .collect()
+        .filter(move |&c| !category_of(c).is_some_and(|cat| opts.contains(cat)))
+}
+
+/// A set of niqqud/diacritic categories, for use with [`remove_with`].
+///
+/// Each flag except [`RemoveOptions::PUNCTUATION`] corresponds to a distinct set of
+/// codepoints in the hebrew diacritics block (`U+0590..=U+05CF`); `PUNCTUATION` covers
+/// `U+05EB..=U+05FF`, outside that block. Flags are combined with bitwise-or.
+/// reference: <https://www.unicode.org/charts/PDF/U0590.pdf>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemoveOptions(u16);
+
+impl RemoveOptions {
+    /// Cantillation marks, "Te'amim" (`U+0591..=U+05AF`)
+    pub const CANTILLATION: Self = Self(1 << 0);
+    /// Vowel points, including sheva and the hataf vowels (`U+05B0..=U+05BB`)
+    pub const VOWELS: Self = Self(1 << 1);
+    /// Dagesh or mapiq (`U+05BC`)
+    pub const DAGESH: Self = Self(1 << 2);
+    /// Meteg (`U+05BD`)
+    pub const METEG: Self = Self(1 << 3);
+    /// Rafe (`U+05BF`)
+    pub const RAFE: Self = Self(1 << 4);
+    /// Shin dot (`U+05C1`)
+    pub const SHIN_DOT: Self = Self(1 << 5);
+    /// Sin dot (`U+05C2`)
+    pub const SIN_DOT: Self = Self(1 << 6);
+    /// Qamats qatan (`U+05C7`)
+    pub const QAMATS_QATAN: Self = Self(1 << 7);
+    /// Any other, rarer codepoint in the diacritics block not covered above
+    pub const OTHER: Self = Self(1 << 8);
+    /// Hebrew punctuation, such as the quotes '״' and '׳' (`U+05EB..=U+05FF`)
+    pub const PUNCTUATION: Self = Self(1 << 9);
+
+    /// All niqqud and cantillation categories, but not punctuation.
+    /// This is what [`remove`] uses.
+    pub const ALL_POINTS_AND_ACCENTS: Self = Self(
+        Self::CANTILLATION.0
+            | Self::VOWELS.0
+            | Self::DAGESH.0
+            | Self::METEG.0
+            | Self::RAFE.0
+            | Self::SHIN_DOT.0
+            | Self::SIN_DOT.0
+            | Self::QAMATS_QATAN.0
+            | Self::OTHER.0,
+    );
+
+    /// Every category, including punctuation. This is what [`remove_thorough`] uses.
+    pub const ALL: Self = Self(Self::ALL_POINTS_AND_ACCENTS.0 | Self::PUNCTUATION.0);
+
+    /// Returns true if `self` includes every flag set in `other`.
+    fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for RemoveOptions {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
 }
 
 /// Returns true if the character is a diacritic
@@ -53,9 +151,222 @@ fn is_special(c: char) -> bool {
     matches!(c, '\u{05EB}'..='\u{05FF}')
 }
 
+/// Returns the [`RemoveOptions`] category a character belongs to, or `None` if it isn't
+/// a hebrew diacritic/punctuation character at all.
+fn category_of(c: char) -> Option<RemoveOptions> {
+    if is_special(c) {
+        return Some(RemoveOptions::PUNCTUATION);
+    }
+    if !is_diacritic(c) {
+        return None;
+    }
+    Some(match c {
+        '\u{0591}'..='\u{05AF}' => RemoveOptions::CANTILLATION,
+        '\u{05B0}'..='\u{05BB}' => RemoveOptions::VOWELS,
+        '\u{05BC}' => RemoveOptions::DAGESH,
+        '\u{05BD}' => RemoveOptions::METEG,
+        '\u{05BF}' => RemoveOptions::RAFE,
+        '\u{05C1}' => RemoveOptions::SHIN_DOT,
+        '\u{05C2}' => RemoveOptions::SIN_DOT,
+        '\u{05C7}' => RemoveOptions::QAMATS_QATAN,
+        _ => RemoveOptions::OTHER,
+    })
+}
+
+/// Transliterates vocalized hebrew text into a simple, general-purpose latin romanization.
+///
+/// Walks the string consonant-by-consonant, attaching each vowel point to the preceding
+/// letter (patah/qamats -> a, tsere/segol/hataf segol -> e, hiriq -> i, holam -> o,
+/// qubuts -> u, sheva is treated as silent), and switching bet/kaf/pe between their soft
+/// and hard pronunciation depending on whether a dagesh is present. A vav carrying a
+/// dagesh (shuruq, e.g. "וּ") is special-cased to the vowel "u" rather than transliterated
+/// as a consonant. Final letter forms transliterate identically to their base form.
+/// Characters that aren't hebrew letters or niqqud are passed through as-is.
+///
+/// Note: this is a simple, single scheme; besides shuruq, it does not attempt to collapse
+/// other matres lectionis (e.g. a holam carried by a silent vav) into the consonant that
+/// precedes them.
+/// ```
+///
+/// let word = niqqud::transliterate("כֶּלֶב");
+/// assert_eq!("kelev", word);
+/// ```
+///
+pub fn transliterate(string: &str) -> String {
+    let mut out = String::with_capacity(string.len());
+    let mut chars = string.chars().peekable();
+    while let Some(c) = chars.next() {
+        // Shuruq ("וּ") is a vav carrying a dagesh used as a mater lectionis for the
+        // vowel /u/, rather than as the consonant vav -- attach it as a bare "u" instead
+        // of transliterating the vav itself.
+        if c == '\u{05D5}' && chars.peek() == Some(&'\u{05BC}') {
+            chars.next();
+            out.push('u');
+            continue;
+        }
+        let Some(letter) = base_latin(c) else {
+            if !is_diacritic(c) {
+                out.push(c);
+            }
+            continue;
+        };
+        let mut dagesh = false;
+        let mut sin_dot = false;
+        let mut vowel = "";
+        while let Some(&next) = chars.peek() {
+            match next {
+                '\u{05BC}' => dagesh = true,
+                '\u{05C1}' => {}
+                '\u{05C2}' => sin_dot = true,
+                '\u{05B2}' | '\u{05B7}' | '\u{05B8}' => vowel = "a",
+                '\u{05B1}' | '\u{05B5}' | '\u{05B6}' => vowel = "e",
+                '\u{05B4}' => vowel = "i",
+                '\u{05B3}' | '\u{05B9}' | '\u{05BA}' | '\u{05C7}' => vowel = "o",
+                '\u{05BB}' => vowel = "u",
+                '\u{05B0}' | '\u{05BD}' | '\u{05BF}' => {}
+                _ => break,
+            }
+            chars.next();
+        }
+        if c == '\u{05E9}' && sin_dot {
+            out.push('s');
+        } else if dagesh {
+            out.push_str(hardened_latin(c).unwrap_or(letter));
+        } else {
+            out.push_str(letter);
+        }
+        out.push_str(vowel);
+    }
+    out
+}
+
+/// Returns the base (no-dagesh) latin transliteration of a hebrew letter (`U+05D0..=U+05EA`).
+fn base_latin(c: char) -> Option<&'static str> {
+    Some(match c {
+        '\u{05D0}' => "",   // alef: silent
+        '\u{05D1}' => "v",  // bet (soft)
+        '\u{05D2}' => "g",
+        '\u{05D3}' => "d",
+        '\u{05D4}' => "h",
+        '\u{05D5}' => "v", // vav
+        '\u{05D6}' => "z",
+        '\u{05D7}' => "ch",
+        '\u{05D8}' => "t",
+        '\u{05D9}' => "y",
+        '\u{05DA}' | '\u{05DB}' => "kh", // final kaf, kaf (soft)
+        '\u{05DC}' => "l",
+        '\u{05DD}' | '\u{05DE}' => "m", // final mem, mem
+        '\u{05DF}' | '\u{05E0}' => "n", // final nun, nun
+        '\u{05E1}' => "s",
+        '\u{05E2}' => "",   // ayin: silent
+        '\u{05E3}' | '\u{05E4}' => "f", // final pe, pe (soft)
+        '\u{05E5}' | '\u{05E6}' => "tz", // final tsadi, tsadi
+        '\u{05E7}' => "q",
+        '\u{05E8}' => "r",
+        '\u{05E9}' => "sh", // shin (sin is handled via the sin dot)
+        '\u{05EA}' => "t",
+        _ => return None,
+    })
+}
+
+/// Returns the hardened (dagesh) latin transliteration of bet/kaf/pe, or `None` for
+/// letters that don't alternate between a soft and hard pronunciation.
+fn hardened_latin(c: char) -> Option<&'static str> {
+    Some(match c {
+        '\u{05D1}' => "b",
+        '\u{05DA}' | '\u{05DB}' => "k",
+        '\u{05E3}' | '\u{05E4}' => "p",
+        _ => return None,
+    })
+}
+
+/// Removes hebrew diacritics from a legacy Windows-1255 (CP1255) encoded byte string.
+///
+/// Unlike [`remove`], this operates directly on the CP1255 bytes -- in that encoding,
+/// niqqud occupy a fixed set of single-byte codepoints rather than unicode combining
+/// marks -- so callers ingesting legacy hebrew corpora don't need to decode to UTF-8 first.
+///
+/// Note: this function does NOT remove hebrew quotes (geresh, gershayim).
+pub fn remove_cp1255(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().copied().filter(|&b| !is_diacritic_cp1255(b)).collect()
+}
+
+/// Removes hebrew diacritics from a legacy Windows-1255 (CP1255) encoded byte string,
+/// while also removing hebrew quotes (geresh, gershayim). See [`remove_cp1255`].
+pub fn remove_cp1255_thorough(bytes: &[u8]) -> Vec<u8> {
+    bytes
+        .iter()
+        .copied()
+        .filter(|&b| !is_diacritic_cp1255(b) && !is_special_cp1255(b))
+        .collect()
+}
+
+/// Returns true if the CP1255 byte represents a niqqud diacritic.
+fn is_diacritic_cp1255(b: u8) -> bool {
+    matches!(b, 0xC0..=0xCC | 0xD1 | 0xD2)
+}
+
+/// Returns true if the CP1255 byte represents a special (HEB) character, such as
+/// geresh (0xD7) or gershayim (0xD8).
+fn is_special_cp1255(b: u8) -> bool {
+    matches!(b, 0xD7 | 0xD8)
+}
+
+/// Returns the number of base (non-diacritic) characters in a string, ignoring niqqud.
+///
+/// This is cheaper than `remove(string).chars().count()`, since it doesn't allocate.
+/// ```
+///
+/// let len = niqqud::len_without_niqqud("נִקּוּד");
+/// assert_eq!(4, len);
+/// ```
+///
+pub fn len_without_niqqud(string: &str) -> usize {
+    string.chars().filter(|&c| !is_diacritic(c)).count()
+}
+
+/// Iterates over the base (non-diacritic) characters of a string, pairing each one with
+/// the diacritics attached to it.
+///
+/// Useful for computing display width or truncating vocalized hebrew to N visible
+/// characters without allocating a de-niqqud'd copy first.
+/// ```
+///
+/// let pairs: Vec<_> = niqqud::base_chars("שָׁלוֹם").collect();
+/// assert_eq!(vec![
+///     ('ש', vec!['\u{05B8}', '\u{05C1}']),
+///     ('ל', vec![]),
+///     ('ו', vec!['\u{05B9}']),
+///     ('ם', vec![]),
+/// ], pairs);
+/// ```
+///
+pub fn base_chars(string: &str) -> impl Iterator<Item = (char, Vec<char>)> + '_ {
+    let mut chars = string.chars().peekable();
+    std::iter::from_fn(move || {
+        let base = chars.find(|&c| !is_diacritic(c))?;
+        let mut diacritics = Vec::new();
+        while let Some(&next) = chars.peek() {
+            if !is_diacritic(next) {
+                break;
+            }
+            diacritics.push(next);
+            chars.next();
+        }
+        Some((base, diacritics))
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::remove;
+    use crate::{base_chars, len_without_niqqud, remove, remove_cp1255, remove_iter, remove_with, transliterate, RemoveOptions};
+
+    #[test]
+    fn test_remove_iter() {
+        let word: String = remove_iter("נִקּוּד").collect();
+
+        assert_eq!("נקוד", word);
+    }
 
     #[test]
     fn test_normal_remove() {
@@ -63,4 +374,62 @@ mod tests {
 
         assert_eq!("שלום עולם", string);
     }
+
+    #[test]
+    fn test_len_without_niqqud() {
+        assert_eq!(4, len_without_niqqud("נִקּוּד"));
+    }
+
+    #[test]
+    fn test_base_chars() {
+        let pairs: Vec<_> = base_chars("שָׁלוֹם").collect();
+
+        assert_eq!(
+            vec![
+                ('ש', vec!['\u{05B8}', '\u{05C1}']),
+                ('ל', vec![]),
+                ('ו', vec!['\u{05B9}']),
+                ('ם', vec![]),
+            ],
+            pairs
+        );
+    }
+
+    #[test]
+    fn test_remove_cp1255() {
+        // cp1255 bytes for "שָׁלוֹם" (shalom, vocalized)
+        let diacrited = [0xF9, 0xC8, 0xD1, 0xEC, 0xE5, 0xC9, 0xED];
+
+        let bytes = remove_cp1255(&diacrited);
+
+        assert_eq!(vec![0xF9, 0xEC, 0xE5, 0xED], bytes);
+    }
+
+    #[test]
+    fn test_transliterate() {
+        let word = transliterate("כֶּלֶב");
+
+        assert_eq!("kelev", word);
+    }
+
+    #[test]
+    fn test_transliterate_hataf_segol() {
+        let word = transliterate("אֱמֶת");
+
+        assert_eq!("emet", word);
+    }
+
+    #[test]
+    fn test_transliterate_shuruq() {
+        let word = transliterate("בּוּ");
+
+        assert_eq!("bu", word);
+    }
+
+    #[test]
+    fn test_remove_with_keeps_shin_dot() {
+        let string = remove_with("שָׁלוֹם", RemoveOptions::VOWELS);
+
+        assert_eq!("שׁלום", string);
+    }
 }